@@ -0,0 +1,245 @@
+//! The [`imply_bundle!`] macro: name a set of implied bounds once, attach it as a
+//! single supertrait everywhere.
+
+/// Declares a named, reusable bundle of implied bounds, for the common case of
+/// a subject that needs more than one unrelated bound (e.g. a value that must
+/// be both `Frob` and `Send`):
+///
+/// ```rust
+/// use imply_hack::imply_bundle;
+///
+/// trait Frob {}
+///
+/// imply_bundle! {
+///     SendFrob<T> = { T: Frob, T: Send, }
+/// }
+/// ```
+///
+/// `SendFrob<T>` can now be attached as a single supertrait, and elaborates every
+/// member bound through the [`Imply`](crate::Imply) chain instead of each
+/// consumer restating the whole `where`-list:
+///
+/// ```rust
+/// # use imply_hack::imply_bundle;
+/// # trait Frob {}
+/// # imply_bundle! { SendFrob<T> = { T: Frob, T: Send, } }
+/// trait Frobber<T>: SendFrob<T> {} // Implies T: Frob and T: Send
+/// ```
+///
+/// Each `Subject: Bound` member becomes a `Self: Imply<Subject, Is: Bound>`
+/// supertrait fragment in the generated trait's `where`-clause, exactly as in
+/// [`imply!`](crate::imply) (and, just as there, list a subject more than once
+/// if it needs more than one bound). Unlike a
+/// plain `imply!`-declared trait, `SendFrob<T>` is blanket-implemented for every
+/// type that actually satisfies its members, so attaching it as a supertrait is
+/// enough on its own; nothing separate needs to implement it.
+///
+/// A member's subject can't be a projection through *another* member (e.g.
+/// `T::Output: Send` depending on a `T: Frob` member listed earlier) — by the
+/// time that supertrait fragment is checked, the other members haven't been
+/// elaborated yet, so `T::Output` doesn't resolve. Each member must be
+/// independently well-formed given only the bundle's own generics.
+#[macro_export]
+macro_rules! imply_bundle {
+    ($vis:vis $name:ident $($header:tt)*) => {
+        $crate::__imply_bundle_internal! {
+            @header
+            vis = [$vis]
+            name = [$name]
+            gen = []
+            rest = [$($header)*]
+        }
+    };
+}
+
+/// Implementation detail of [`imply_bundle!`]. Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imply_bundle_internal {
+    // No generics, straight into the `= { ... }` predicate body.
+    (
+        @header
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = []
+        rest = [= { $($preds:tt)* }]
+    ) => {
+        $crate::__imply_bundle_internal! {
+            @split
+            vis = [$vis]
+            name = [$name]
+            gen = []
+            super = []
+            bounds = []
+            rest = [$($preds)*]
+        }
+    };
+
+    // Entering the generics list: switch to accumulating its inner tokens
+    // separately, so they can be spliced into the blanket impl's own generics
+    // later without the enclosing `<`/`>` in the way.
+    (
+        @header
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = []
+        rest = [< $($rest:tt)*]
+    ) => {
+        $crate::__imply_bundle_internal! {
+            @generics
+            vis = [$vis]
+            name = [$name]
+            gen = []
+            rest = [$($rest)*]
+        }
+    };
+
+    // Closing `>`: generics are done, now expect `= { ... }`.
+    (
+        @generics
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        rest = [> = { $($preds:tt)* }]
+    ) => {
+        $crate::__imply_bundle_internal! {
+            @split
+            vis = [$vis]
+            name = [$name]
+            gen = [$($gen)*]
+            super = []
+            bounds = []
+            rest = [$($preds)*]
+        }
+    };
+
+    // Still inside the generics list: stash one token and keep looking for the
+    // closing `>`. Matching token-by-token (rather than `$(<$($gen:tt)*>)?` in
+    // one go) avoids a local-ambiguity error, since `$gen:tt` could otherwise
+    // also swallow the closing `>` itself.
+    (
+        @generics
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        rest = [$next:tt $($rest:tt)*]
+    ) => {
+        $crate::__imply_bundle_internal! {
+            @generics
+            vis = [$vis]
+            name = [$name]
+            gen = [$($gen)* $next]
+            rest = [$($rest)*]
+        }
+    };
+
+    // One member predicate: `Subject: Bound, ...`
+    (
+        @split
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        super = [$($super:tt)*]
+        bounds = [$($bounds:tt)*]
+        rest = [
+            $subject:ty : $bound:path ,
+            $($rest:tt)*
+        ]
+    ) => {
+        $crate::__imply_bundle_internal! {
+            @split
+            vis = [$vis]
+            name = [$name]
+            gen = [$($gen)*]
+            super = [$($super)* Self: $crate::Imply<$subject, Is: $bound>,]
+            bounds = [$($bounds)* $subject: $bound,]
+            rest = [$($rest)*]
+        }
+    };
+
+    // Same, but the last member before the closing `}` (trailing comma
+    // optional): put the comma back and recurse into the arm above.
+    (
+        @split
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        super = [$($super:tt)*]
+        bounds = [$($bounds:tt)*]
+        rest = [
+            $subject:ty : $bound:path
+        ]
+    ) => {
+        $crate::__imply_bundle_internal! {
+            @split
+            vis = [$vis]
+            name = [$name]
+            gen = [$($gen)*]
+            super = [$($super)*]
+            bounds = [$($bounds)*]
+            rest = [
+                $subject: $bound,
+            ]
+        }
+    };
+
+    // No predicates left, no generics: emit the marker trait and its blanket
+    // impl. The member bounds are restated on the marker trait itself (not
+    // just the blanket impl), for the same reason `__imply_internal!` restates
+    // its own predicates (see its comment) — a member bound on a projection
+    // through an earlier member would otherwise fail to resolve here, inside
+    // the very declaration that's supposed to imply it.
+    //
+    // Lifted members are stated as `Self: Imply<Subject, Is: Bound>` in the
+    // `where`-clause rather than in the supertrait list after the colon: a
+    // `where Self: ..` bound is just as much a supertrait as one listed after
+    // `:`, and spelling it this way means there's no first-fragment to track
+    // separately when joining zero or more of them with `+`.
+    (
+        @split
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = []
+        super = [$($super:tt)*]
+        bounds = [$($bounds:tt)*]
+        rest = []
+    ) => {
+        $vis trait $name
+        where
+            $($super)*
+            $($bounds)*
+        {
+        }
+
+        impl<__ImplyBundleSelf> $name for __ImplyBundleSelf
+        where
+            $($bounds)*
+        {
+        }
+    };
+
+    // No predicates left, with generics: same, but splice the subject's own
+    // generics into both the trait and the blanket impl.
+    (
+        @split
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)+]
+        super = [$($super:tt)*]
+        bounds = [$($bounds:tt)*]
+        rest = []
+    ) => {
+        $vis trait $name<$($gen)*>
+        where
+            $($super)*
+            $($bounds)*
+        {
+        }
+
+        impl<$($gen)*, __ImplyBundleSelf> $name<$($gen)*> for __ImplyBundleSelf
+        where
+            $($bounds)*
+        {
+        }
+    };
+}
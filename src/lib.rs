@@ -1,6 +1,7 @@
 //! Add implied bounds to your traits by adding [`Imply`] as a super trait:
 //!
 //! ```rust
+//! # use imply_hack::Imply;
 //! trait Bound {}
 //!
 //! trait MyTrait<T>: Imply<T, Is: Bound> {} // Implies T: Bound
@@ -17,7 +18,7 @@
 //!
 //! ```rust
 //! trait MyTrait<T> {
-//!     fn do_the_thing(value: &T);
+//!     fn do_the_thing(&self, value: &T);
 //! }
 //!
 //! struct Foo;
@@ -46,6 +47,9 @@
 //! Now, this is all well and good. But suppose we now want to make `run` generic over any `FooUser`.
 //!
 //! ```rust
+//! # trait MyTrait<T> { fn do_the_thing(&self, value: &T); }
+//! # struct Foo;
+//! # struct MyFooUser;
 //! trait FooUser<T>
 //! {
 //!     fn use_value(&self, value: &T);
@@ -69,19 +73,24 @@
 //! > function, but a trait implementation. One of many.
 //!
 //! ```rust
+//! # trait MyTrait<T> { fn do_the_thing(&self, value: &T); }
+//! # struct Foo;
+//! # trait FooUser<T> { fn use_value(&self, value: &T); }
 //! fn run<T, U>(value: T, user: U)
 //! where
 //!     U: FooUser<T>,
 //!     Foo: MyTrait<T>, // We really want to get rid of this.
 //! {
-//!     user.use_value(&value)
+//!     user.use_value(&value);
 //!     Foo.do_the_thing(&value);
 //! }
 //! ```
 //!
 //! If you've run into similar situations before, you might be tempted to do:
 //!
-//! ```rust
+//! ```rust,compile_fail
+//! # trait MyTrait<T> {}
+//! # struct Foo;
 //! trait FooUser<T>
 //! where
 //!     Foo: MyTrait<T>
@@ -138,6 +147,7 @@
 //! that super trait (which we set equal to `T`), such that it satisfies `Bound`. This looks like this:
 //!
 //! ```rust
+//! # trait Bound {}
 //! trait Imply {
 //!     type Is;
 //! }
@@ -152,6 +162,7 @@
 //! This is still a bit annoying to use. Refining the design a bit we get:
 //!
 //! ```rust
+//! # trait Bound {}
 //! trait Imply<T>: ImplyInner<T, Is = T> {}
 //!
 //! trait ImplyInner<T> {
@@ -162,17 +173,61 @@
 //! ```
 //!
 //! Then, add a few blanket impls and we have `imply_hack`!
+//!
+//! ## Declaring traits without repeating `where`-clauses
+//!
+//! Writing `Imply<Subject, Is: Bound>` by hand is easy to get wrong the first
+//! time (is it `T: Bound` or `Foo: Bound`?). The [`imply!`] macro lets you write
+//! the natural `where`-clause instead and lifts it into the right supertraits for
+//! you.
+//!
+//! ## Implying outlives bounds
+//!
+//! `Imply` also covers lifetimes: `Imply<T, Is: 'a>` implies `T: 'a` the same way
+//! it implies a trait bound, and [`ImplyOutlives`] spells that out without having
+//! to remember the `Is: 'a` syntax. The region-to-region case `'a: 'b` has no
+//! implied-bound-friendly encoding (see [`ImplyOutlives`]'s docs for why), so it
+//! still needs to be written directly in a `where`-clause.
+//!
+//! ## Bundling bounds
+//!
+//! [`imply_bundle!`] names a whole set of implied bounds once, so it can be
+//! attached as a single supertrait instead of every trait restating the same
+//! `where`-list.
+
+mod bundle;
+mod macros;
+mod outlives;
+
+pub use outlives::ImplyOutlives;
 
 /// Creates an implied bound when applied as a supertrait.
 ///
 /// ```rust
+/// # use imply_hack::Imply;
+/// trait Bound {}
+///
 /// trait MyTrait<T>: Imply<T, Is: Bound> {} // Implies T: Bound
 /// ```
+///
+/// If the bound named after `Is:` isn't satisfied, the compiler reports that bound
+/// itself (e.g. "the trait bound `NotBound: Bound` is not satisfied"), pointing at
+/// the `Is: Bound` it comes from. `Imply` is implemented for every sized type, so
+/// the `#[diagnostic::on_unimplemented]` message below only shows where no `Imply`
+/// impl applies. [`imply!`]'s `#[imply(message = "...")]` attaches a custom
+/// message to a declared trait instead.
+#[diagnostic::on_unimplemented(
+    message = "`{T}` does not satisfy a bound implied by `{Self}`",
+    note = "the bound comes from an `Imply<{T}, Is: ..>` supertrait; check the trait's `where`-clause"
+)]
 pub trait Imply<T>: sealed::ImplyInner<T, Is = T> {}
 
 impl<T, U> Imply<T> for U {}
 
 mod sealed {
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` does not satisfy a bound implied through `Imply<{T}>`"
+    )]
     pub trait ImplyInner<T> {
         type Is;
     }
@@ -0,0 +1,456 @@
+//! The [`imply!`] macro: write a natural `where`-clause, get implied-bound
+//! supertraits for free.
+
+/// Declares a trait whose `where`-clause predicates are lifted into implied-bound
+/// supertraits via [`Imply`](crate::Imply), instead of every caller having to
+/// restate them.
+///
+/// ```rust
+/// use imply_hack::imply;
+///
+/// trait Bound {}
+/// struct Foo;
+/// trait MyTrait<T> {}
+///
+/// imply! {
+///     trait FooUser<T>
+///     where
+///         T: Bound,
+///         Foo: MyTrait<T>,
+///     {
+///         fn use_value(&self, value: &T);
+///     }
+/// }
+/// ```
+///
+/// expands to (roughly):
+///
+/// ```rust
+/// # use imply_hack::Imply;
+/// # trait Bound {}
+/// # struct Foo;
+/// # trait MyTrait<T> {}
+/// trait FooUser<T>
+/// where
+///     Self: Imply<T, Is: Bound>,
+///     Self: Imply<Foo, Is: MyTrait<T>>,
+/// {
+///     fn use_value(&self, value: &T);
+/// }
+/// ```
+///
+/// Each `Subject: Bound` predicate becomes a `Self: Imply<Subject, Is: Bound>`
+/// supertrait fragment in the generated trait's `where`-clause (a `where Self:
+/// ..` bound is just as much a supertrait as one listed after the trait's own
+/// `:`). Generics, lifetimes and GATs on the trait header are passed through
+/// unchanged, as is the method body.
+///
+/// Each predicate names exactly one bound. If a subject needs more than one
+/// bound, just list it again — repeating `Subject: BoundA,` and
+/// `Subject: BoundB,` produces two separate `Imply<Subject, ..>` supertrait
+/// fragments, both of which constrain the very same `Is` associated type, so
+/// the effect is identical to a single `Imply<Subject, Is: BoundA + BoundB>`:
+///
+/// ```rust
+/// use imply_hack::imply;
+///
+/// trait BoundA {}
+/// trait BoundB {}
+///
+/// imply! {
+///     trait FooUser<T>
+///     where
+///         T: BoundA,
+///         T: BoundB,
+///     {
+///         fn use_value(&self, value: &T);
+///     }
+/// }
+/// ```
+///
+/// Not every predicate is worth lifting. For example, lifting a projection like
+/// `T::Item: Clone` together with `T: HasItem` compiles, but then `T: HasItem`
+/// is no longer implied for code that names the trait. Mark such predicates
+/// with `#[imply(verbatim)]` to keep them as plain `where` bounds on the
+/// generated trait instead of lifting them; a verbatim bound is not implied:
+///
+/// ```rust
+/// use imply_hack::imply;
+///
+/// trait HasItem {
+///     type Item;
+/// }
+///
+/// imply! {
+///     trait ItemUser<T>
+///     where
+///         T: HasItem,
+///         #[imply(verbatim)]
+///         T::Item: Clone,
+///     {
+///         fn use_item(&self, item: &T::Item);
+///     }
+/// }
+/// ```
+///
+/// A lifted predicate can also carry `#[imply(message = "...")]` to attach a
+/// custom [`diagnostic::on_unimplemented`](https://doc.rust-lang.org/reference/attributes/diagnostics.html#the-diagnosticon_unimplemented-attribute)
+/// message to the generated trait. The message belongs to the trait as a whole,
+/// not to the predicate that carries it: it replaces the compiler's own message
+/// wherever a type does not implement the trait. Where a type does implement the
+/// trait but a lifted predicate is unmet, the compiler still reports that
+/// predicate as a plain "the trait bound ... is not satisfied" error. If more
+/// than one predicate carries a message, the first one wins.
+///
+/// ```rust
+/// use imply_hack::imply;
+///
+/// trait Bound {}
+///
+/// imply! {
+///     trait FooUser<T>
+///     where
+///         #[imply(message = "`{T}` must implement `Bound` to use `FooUser`")]
+///         T: Bound,
+///     {
+///         fn use_value(&self, value: &T);
+///     }
+/// }
+/// ```
+///
+/// Naming the trait for a type that does not implement it then shows that
+/// message instead of the generic "the trait bound ... is not satisfied" one:
+///
+/// ```rust,compile_fail
+/// use imply_hack::imply;
+///
+/// trait Bound {}
+/// struct NotBound;
+///
+/// imply! {
+///     trait FooUser<T>
+///     where
+///         #[imply(message = "`{T}` must implement `Bound` to use `FooUser`")]
+///         T: Bound,
+///     {
+///         fn use_value(&self, value: &T);
+///     }
+/// }
+///
+/// struct Foo;
+///
+/// fn use_it<T, F: FooUser<T>>() {}
+/// use_it::<NotBound, Foo>(); // Error: `NotBound` must implement `Bound` to use `FooUser`
+/// ```
+#[macro_export]
+macro_rules! imply {
+    (
+        $(#[$meta:meta])*
+        $vis:vis trait $name:ident $($header:tt)*
+    ) => {
+        $crate::__imply_internal! {
+            @header
+            meta = [$(#[$meta])*]
+            vis = [$vis]
+            name = [$name]
+            gen = []
+            rest = [$($header)*]
+        }
+    };
+}
+
+/// Implementation detail of [`imply!`]. Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __imply_internal {
+    // No `where`-clause: nothing to lift, emit the trait as-is.
+    (
+        @header
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        rest = [{ $($body:tt)* }]
+    ) => {
+        $($meta)*
+        $vis trait $name $($gen)* { $($body)* }
+    };
+
+    // Found the `where` keyword: everything before it was generics, start
+    // splitting the predicates that follow.
+    (
+        @header
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        rest = [where $($where:tt)*]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$name $($gen)*]
+            super = []
+            preds = []
+            msgs = []
+            rest = [$($where)*]
+        }
+    };
+
+    // Still inside the (possibly absent) generics: stash one token and keep
+    // looking for `where` or the trait body. Matching token-by-token (rather
+    // than `$(<$($gen:tt)*>)?` in one go) avoids a local-ambiguity error, since
+    // `$gen:tt` could otherwise also swallow the closing `>` itself.
+    (
+        @header
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$name:ident]
+        gen = [$($gen:tt)*]
+        rest = [$next:tt $($rest:tt)*]
+    ) => {
+        $crate::__imply_internal! {
+            @header
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$name]
+            gen = [$($gen)* $next]
+            rest = [$($rest)*]
+        }
+    };
+
+    // A liftable predicate with a custom diagnostic message.
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$($msgs:tt)*]
+        rest = [
+            #[imply(message = $msg:literal)]
+            $subject:ty : $bound:path ,
+            $($rest:tt)*
+        ]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$($name)*]
+            super = [$($super)* Self: $crate::Imply<$subject, Is: $bound>,]
+            preds = [$($preds)* $subject: $bound,]
+            msgs = [$($msgs)* $msg,]
+            rest = [$($rest)*]
+        }
+    };
+
+    // Same, but the last predicate before the trait body: the trailing comma
+    // is optional here, since nothing follows it. Put the comma back and
+    // recurse into the arm above.
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$($msgs:tt)*]
+        rest = [
+            #[imply(message = $msg:literal)]
+            $subject:ty : $bound:path
+            { $($body:tt)* }
+        ]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$($name)*]
+            super = [$($super)*]
+            preds = [$($preds)*]
+            msgs = [$($msgs)*]
+            rest = [
+                #[imply(message = $msg)]
+                $subject: $bound,
+                { $($body)* }
+            ]
+        }
+    };
+
+    // A predicate explicitly marked to stay as a plain `where` bound.
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$($msgs:tt)*]
+        rest = [
+            #[imply(verbatim)]
+            $subject:ty : $bound:path ,
+            $($rest:tt)*
+        ]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$($name)*]
+            super = [$($super)*]
+            preds = [$($preds)* $subject: $bound,]
+            msgs = [$($msgs)*]
+            rest = [$($rest)*]
+        }
+    };
+
+    // Same, but the last predicate before the trait body (trailing comma
+    // optional): put the comma back and recurse into the arm above.
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$($msgs:tt)*]
+        rest = [
+            #[imply(verbatim)]
+            $subject:ty : $bound:path
+            { $($body:tt)* }
+        ]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$($name)*]
+            super = [$($super)*]
+            preds = [$($preds)*]
+            msgs = [$($msgs)*]
+            rest = [
+                #[imply(verbatim)]
+                $subject: $bound,
+                { $($body)* }
+            ]
+        }
+    };
+
+    // A plain liftable predicate: `Subject: Bound, ...`
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$($msgs:tt)*]
+        rest = [
+            $subject:ty : $bound:path ,
+            $($rest:tt)*
+        ]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$($name)*]
+            super = [$($super)* Self: $crate::Imply<$subject, Is: $bound>,]
+            preds = [$($preds)* $subject: $bound,]
+            msgs = [$($msgs)*]
+            rest = [$($rest)*]
+        }
+    };
+
+    // Same, but the last predicate before the trait body (trailing comma
+    // optional): put the comma back and recurse into the arm above.
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$($msgs:tt)*]
+        rest = [
+            $subject:ty : $bound:path
+            { $($body:tt)* }
+        ]
+    ) => {
+        $crate::__imply_internal! {
+            @split
+            meta = [$($meta)*]
+            vis = [$vis]
+            name = [$($name)*]
+            super = [$($super)*]
+            preds = [$($preds)*]
+            msgs = [$($msgs)*]
+            rest = [
+                $subject: $bound,
+                { $($body)* }
+            ]
+        }
+    };
+
+    // Done splitting predicates, no custom messages: emit the trait header as-is.
+    //
+    // Every predicate — lifted or verbatim — is restated here as a plain
+    // `where` bound on the generated trait itself, in addition to whatever got
+    // lifted into `super`. Implied bounds only flow *out* to callers through a
+    // supertrait; they're invisible to the rest of this very trait's own
+    // header, so a later verbatim predicate that projects through an earlier
+    // subject (e.g. `T::Item: Clone` after `T: HasItem`) would otherwise fail
+    // to resolve.
+    //
+    // Lifted predicates are stated as `Self: Imply<Subject, Is: Bound>` in the
+    // `where`-clause rather than in the supertrait list after the colon: a
+    // `where Self: ..` bound is just as much a supertrait as one listed after
+    // `:`, and spelling it this way means there's no first-fragment to track
+    // separately when joining zero or more of them with `+`.
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = []
+        rest = [{ $($body:tt)* }]
+    ) => {
+        $($meta)*
+        $vis trait $($name)*
+        where
+            $($super)*
+            $($preds)*
+        {
+            $($body)*
+        }
+    };
+
+    // Done splitting predicates, with at least one custom message: also emit
+    // `#[diagnostic::on_unimplemented]` on the generated trait, using the first
+    // message collected (the attribute only accepts a single literal).
+    (
+        @split
+        meta = [$($meta:tt)*]
+        vis = [$vis:vis]
+        name = [$($name:tt)*]
+        super = [$($super:tt)*]
+        preds = [$($preds:tt)*]
+        msgs = [$msg:literal, $($rest:literal,)*]
+        rest = [{ $($body:tt)* }]
+    ) => {
+        $($meta)*
+        #[diagnostic::on_unimplemented(message = $msg)]
+        $vis trait $($name)*
+        where
+            $($super)*
+            $($preds)*
+        {
+            $($body)*
+        }
+    };
+}
@@ -0,0 +1,45 @@
+//! Implied outlives bounds: `T: 'a`.
+
+use crate::Imply;
+
+/// Implies `T: 'a` when applied as a supertrait.
+///
+/// ```rust
+/// use imply_hack::ImplyOutlives;
+///
+/// trait Ref<'a, T>: ImplyOutlives<'a, T> {} // Implies T: 'a
+///
+/// fn use_ref<'a, T, R: Ref<'a, T>>(_: &'a T) {
+///     // `T: 'a` is available here without restating it.
+/// }
+/// ```
+///
+/// Only a bare type parameter works as the subject: the blanket impl below
+/// discharges `T: 'a` through [`Imply`]'s own elaboration rather than asserting it
+/// directly, so the check is deferred to each trait that names `ImplyOutlives` as
+/// a supertrait. A subject that mixes two lifetimes together instead (the classic
+/// `&'a (): 'b` encoding of `'a: 'b`) has its outlives requirement checked against
+/// its own structure right away, before elaboration gets a chance to run, so it
+/// can't be expressed through `ImplyOutlives` at all. This crate has no
+/// implied-bound-friendly spelling of `'a: 'b`; write it directly in a
+/// `where`-clause instead.
+///
+/// `T` is required to be `Sized` here, matching [`Imply`]'s own (implicitly
+/// `Sized`) subject type parameter. `Self` is implicitly `Sized` too, again
+/// matching `Imply`'s own blanket impl.
+///
+/// The `&'a (): 'b` encoding of `'a: 'b` fails immediately, before elaboration
+/// gets a chance to run:
+///
+/// ```rust,compile_fail
+/// use imply_hack::ImplyOutlives;
+///
+/// trait Both<'a, 'b>: ImplyOutlives<'b, &'a ()> {} // Error: E0477
+/// ```
+///
+/// ```text
+/// error[E0477]: the type `&'a ()` does not fulfill the required lifetime
+/// ```
+pub trait ImplyOutlives<'a, T>: Imply<T, Is: 'a> {}
+
+impl<'a, T, U> ImplyOutlives<'a, T> for U where U: Imply<T, Is: 'a> {}
@@ -0,0 +1,35 @@
+//! Consumer side of the cross-crate elaboration fixture (see `../README.md`).
+//!
+//! Relies on the bounds `helper`'s traits imply, without restating any of
+//! them, to prove elaboration survives the crate edge.
+
+use imply_hack_cross_crate_helper::{
+    HasAssoc, MyBound, NeedsAssocBound, NeedsBound, NeedsNestedProjectionBound, Super,
+};
+
+fn needs_bound<T>()
+where
+    T: MyBound,
+{
+}
+
+/// `T: MyBound` is available here purely from `N: NeedsBound<T>` — it's never
+/// restated.
+pub fn use_needs_bound<T, N: NeedsBound<T>>() {
+    needs_bound::<T>();
+}
+
+/// `T::Assoc: MyBound` is available here purely from `N: NeedsAssocBound<T>`.
+pub fn use_needs_assoc_bound<T: HasAssoc, N: NeedsAssocBound<T>>() {
+    needs_bound::<T::Assoc>();
+}
+
+/// `<T::Assoc as Super>::SuperAssoc: MyBound` is available here purely from
+/// `N: NeedsNestedProjectionBound<T>`.
+pub fn use_needs_nested_projection_bound<T, N: NeedsNestedProjectionBound<T>>()
+where
+    T: HasAssoc,
+    T::Assoc: Super,
+{
+    needs_bound::<<T::Assoc as Super>::SuperAssoc>();
+}
@@ -0,0 +1,54 @@
+//! If this compiles, `helper`'s implied bounds elaborated across the crate
+//! edge into `consumer` (see `../../README.md`).
+
+use imply_hack_cross_crate_consumer::{
+    use_needs_assoc_bound, use_needs_bound, use_needs_nested_projection_bound,
+};
+use imply_hack_cross_crate_helper::{
+    HasAssoc, MyBound, NeedsAssocBound, NeedsBound, NeedsNestedProjectionBound, Super,
+};
+
+struct Unit;
+impl MyBound for Unit {}
+
+struct Foo;
+impl HasAssoc for Foo {
+    type Assoc = Unit;
+}
+
+struct Bar;
+impl HasAssoc for Bar {
+    type Assoc = Baz;
+}
+struct Baz;
+impl Super for Baz {
+    type SuperAssoc = Unit;
+}
+
+// Each implementor only needs to satisfy the bound for the *specific* subject
+// it's implemented against — that obligation is exactly what `Imply` enforces
+// at the impl site, on the implementor's side of the crate edge.
+
+struct NeedsBoundImpl;
+impl NeedsBound<Unit> for NeedsBoundImpl {}
+
+struct NeedsAssocBoundImpl;
+impl NeedsAssocBound<Foo> for NeedsAssocBoundImpl {}
+
+struct NeedsNestedProjectionBoundImpl;
+impl NeedsNestedProjectionBound<Bar> for NeedsNestedProjectionBoundImpl {}
+
+#[test]
+fn supertrait_elaboration() {
+    use_needs_bound::<Unit, NeedsBoundImpl>();
+}
+
+#[test]
+fn associated_bound_elaboration() {
+    use_needs_assoc_bound::<Foo, NeedsAssocBoundImpl>();
+}
+
+#[test]
+fn nested_projection_elaboration() {
+    use_needs_nested_projection_bound::<Bar, NeedsNestedProjectionBoundImpl>();
+}
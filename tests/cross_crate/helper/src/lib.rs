@@ -0,0 +1,33 @@
+//! Helper side of the cross-crate elaboration fixture (see `../README.md`).
+//!
+//! Defines a handful of `Imply`-based traits that a sibling `consumer` crate
+//! relies on without restating their bounds, covering supertrait elaboration,
+//! associated-bound elaboration, and nested-projection elaboration.
+
+use imply_hack::Imply;
+
+pub trait MyBound {}
+
+pub trait HasAssoc {
+    type Assoc;
+}
+
+pub trait Super {
+    type SuperAssoc;
+}
+
+/// Supertrait elaboration: implies `T: MyBound`.
+pub trait NeedsBound<T>: Imply<T, Is: MyBound> {}
+
+/// Associated-bound elaboration: implies `T::Assoc: MyBound` through an
+/// intermediate projection.
+pub trait NeedsAssocBound<T: HasAssoc>: Imply<T::Assoc, Is: MyBound> {}
+
+/// Nested-projection elaboration: implies `<T::Assoc as Super>::SuperAssoc:
+/// MyBound`.
+pub trait NeedsNestedProjectionBound<T>: Imply<<T::Assoc as Super>::SuperAssoc, Is: MyBound>
+where
+    T: HasAssoc,
+    T::Assoc: Super,
+{
+}